@@ -0,0 +1,30 @@
+//! Demonstrates that cloning a `GSet` is constant-time regardless of size,
+//! thanks to the structurally shared hash-array-mapped trie behind it.
+
+#![feature(test)]
+
+extern crate crdt;
+extern crate test;
+
+use crdt::set::GSet;
+use test::Bencher;
+
+fn populated(n: u32) -> GSet<u32> {
+    let mut set = GSet::new();
+    for i in 0..n {
+        set.insert(i);
+    }
+    set
+}
+
+#[bench]
+fn clone_small(b: &mut Bencher) {
+    let set = populated(16);
+    b.iter(|| set.clone());
+}
+
+#[bench]
+fn clone_large(b: &mut Bencher) {
+    let set = populated(100_000);
+    b.iter(|| set.clone());
+}
@@ -1,19 +1,29 @@
 use std::cmp::Ordering::{self, Equal, Greater, Less};
-use std::collections::HashSet;
-use std::hash::Hash;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FromIterator;
 
 #[cfg(any(quickcheck, test))]
 use quickcheck::{Arbitrary, Gen};
 
+use set::hamt::{self, HamtSet};
 use Crdt;
 
 /// A grow-only set.
-#[derive(Debug, Default)]
-pub struct GSet<T>
+///
+/// The set is backed by an immutable hash-array-mapped trie, so `clone` shares
+/// the whole structure in `O(1)` and `merge` only reallocates along the paths
+/// that differ. This makes it practical to keep many concurrent replica
+/// versions in memory at once.
+///
+/// The hasher is parameterized by `S` so applications can plug in a faster or
+/// deterministic `BuildHasher` without wrapping the set.
+pub struct GSet<T, S = RandomState>
 where
-    T: Eq + Hash,
+    T: Clone + Eq + Hash,
 {
-    elements: HashSet<T>,
+    elements: HamtSet<T, S>,
 }
 
 /// An insert operation over `GSet` CRDTs.
@@ -22,11 +32,25 @@ pub struct GSetOp<T> {
     element: T,
 }
 
-impl<T> GSet<T>
+/// A batch of `GSet` insert operations.
+///
+/// A delta accumulates operations and joins with other deltas by union, so a
+/// peer can collect the operations it has seen since the last anti-entropy
+/// round and ship them as one unit. Applying a delta is equivalent to applying
+/// each of its operations in turn.
+pub struct GSetDelta<T, S = RandomState>
+where
+    T: Clone + Eq + Hash,
+{
+    elements: GSet<T, S>,
+}
+
+impl<T, S> GSet<T, S>
 where
     T: Clone + Eq + Hash,
+    S: BuildHasher,
 {
-    /// Create a new grow-only set.
+    /// Create a new grow-only set using the default hasher.
     ///
     /// ### Example
     ///
@@ -36,9 +60,40 @@ where
     /// let mut set = GSet::<i32>::new();
     /// assert!(set.is_empty());
     /// ```
-    pub fn new() -> GSet<T> {
+    pub fn new() -> GSet<T, S>
+    where
+        S: Default,
+    {
+        GSet {
+            elements: HamtSet::new(),
+        }
+    }
+
+    /// Create a new grow-only set that hashes with the given `BuildHasher`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::RandomState;
+    /// use crdt::set::GSet;
+    ///
+    /// let mut set: GSet<i32, RandomState> = GSet::with_hasher(RandomState::new());
+    /// set.insert(1);
+    /// assert!(set.contains(&1));
+    /// ```
+    pub fn with_hasher(hasher: S) -> GSet<T, S> {
+        GSet {
+            elements: HamtSet::with_hasher(hasher),
+        }
+    }
+
+    /// Create an empty set that shares this set's hasher.
+    fn empty_like(&self) -> GSet<T, S>
+    where
+        S: Clone,
+    {
         GSet {
-            elements: HashSet::new(),
+            elements: self.elements.empty_like(),
         }
     }
 
@@ -49,7 +104,7 @@ where
     /// ```
     /// use crdt::set::GSet;
     ///
-    /// let mut set = GSet::new();
+    /// let mut set: GSet<&str> = GSet::new();
     /// set.insert("first-element");
     /// assert!(set.contains(&"first-element"));
     /// ```
@@ -61,6 +116,7 @@ where
         }
     }
 
+
     /// Returns the number of elements in the set.
     pub fn len(&self) -> usize {
         self.elements.len()
@@ -76,18 +132,251 @@ where
         self.elements.is_empty()
     }
 
-    pub fn is_subset(&self, other: &GSet<T>) -> bool {
-        self.elements.is_subset(&other.elements)
+    pub fn is_subset(&self, other: &GSet<T, S>) -> bool {
+        self.elements.len() <= other.elements.len()
+            && self.iter().all(|element| other.contains(element))
+    }
+
+    pub fn is_disjoint(&self, other: &GSet<T, S>) -> bool {
+        self.iter().all(|element| !other.contains(element))
+    }
+
+    /// An iterator visiting all elements in arbitrary order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use crdt::set::GSet;
+    ///
+    /// let mut set: GSet<i32> = GSet::new();
+    /// set.insert(1i32);
+    /// set.insert(2);
+    /// assert_eq!(2, set.iter().count());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    /// The union, i.e. all the values in `self` or `other`, as a fresh set.
+    pub fn union(&self, other: &GSet<T, S>) -> GSet<T, S>
+    where
+        S: Clone,
+    {
+        let mut set = self.clone();
+        set.extend(other.iter().cloned());
+        set
+    }
+
+    /// The intersection, i.e. the values that are both in `self` and `other`,
+    /// as a fresh set.
+    pub fn intersection(&self, other: &GSet<T, S>) -> GSet<T, S>
+    where
+        S: Clone,
+    {
+        let mut set = self.empty_like();
+        set.extend(self.iter().filter(|element| other.contains(element)).cloned());
+        set
+    }
+
+    /// The difference, i.e. the values that are in `self` but not in `other`,
+    /// as a fresh set.
+    pub fn difference(&self, other: &GSet<T, S>) -> GSet<T, S>
+    where
+        S: Clone,
+    {
+        let mut set = self.empty_like();
+        set.extend(self.iter().filter(|element| !other.contains(element)).cloned());
+        set
+    }
+
+    /// The symmetric difference, i.e. the values that are in `self` or in
+    /// `other` but not in both, as a fresh set.
+    pub fn symmetric_difference(&self, other: &GSet<T, S>) -> GSet<T, S>
+    where
+        S: Clone,
+    {
+        let mut set = self.difference(other);
+        set.extend(other.difference(self).into_iter());
+        set
+    }
+
+    /// Return the minimal delta needed to bring `known` up to date with `self`,
+    /// i.e. the elements `self` has that `known` lacks.
+    ///
+    /// Merging the delta into `known` yields the same state as merging the
+    /// whole of `self`, but transfers only the differing elements.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use crdt::set::GSet;
+    /// # use crdt::Crdt;
+    /// let mut known: GSet<i32> = GSet::new();
+    /// known.insert(1i32);
+    ///
+    /// let mut ahead = known.clone();
+    /// ahead.insert(2);
+    ///
+    /// let delta = ahead.delta_since(&known);
+    /// assert_eq!(1, delta.len());
+    /// known.merge(delta);
+    /// assert!(known.contains(&2));
+    /// ```
+    pub fn delta_since(&self, known: &GSet<T, S>) -> GSet<T, S>
+    where
+        S: Clone,
+    {
+        self.difference(known)
+    }
+}
+
+impl<T, S> GSetDelta<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    /// Create an empty delta using the default hasher.
+    pub fn new() -> GSetDelta<T, S>
+    where
+        S: Default,
+    {
+        GSetDelta {
+            elements: GSet::new(),
+        }
+    }
+
+    /// Record an operation into the delta.
+    pub fn record(&mut self, op: GSetOp<T>) {
+        self.elements.insert(op.element);
+    }
+
+    /// Join another delta into this one by union.
+    pub fn join(&mut self, other: GSetDelta<T, S>) {
+        self.elements.merge(other.elements);
+    }
+
+    /// Returns the number of distinct operations batched in the delta.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if the delta carries no operations.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Apply every operation in the delta to `target`.
+    pub fn apply(self, target: &mut GSet<T, S>) {
+        for element in self.elements {
+            target.insert(element);
+        }
+    }
+}
+
+impl<T, S> Default for GSetDelta<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn default() -> GSetDelta<T, S> {
+        GSetDelta::new()
+    }
+}
+
+impl<T, S> fmt::Debug for GSetDelta<T, S>
+where
+    T: fmt::Debug + Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.elements.fmt(f)
+    }
+}
+
+impl<T, S> FromIterator<GSetOp<T>> for GSetDelta<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iterable: I) -> GSetDelta<T, S>
+    where
+        I: IntoIterator<Item = GSetOp<T>>,
+    {
+        let mut delta = GSetDelta::new();
+        for op in iterable {
+            delta.record(op);
+        }
+        delta
+    }
+}
+
+/// An iterator over the elements of a `GSet`.
+pub type Iter<'a, T> = hamt::Iter<'a, T>;
+
+/// An owning iterator over the elements of a `GSet`.
+pub type IntoIter<T> = ::std::vec::IntoIter<T>;
+
+impl<'a, T, S> IntoIterator for &'a GSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.elements.iter()
+    }
+}
+
+impl<T, S> IntoIterator for GSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        let elements: Vec<T> = self.elements.iter().cloned().collect();
+        elements.into_iter()
+    }
+}
+
+impl<T, S> FromIterator<T> for GSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iterable: I) -> GSet<T, S>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut set = GSet::new();
+        set.extend(iterable);
+        set
     }
+}
 
-    pub fn is_disjoint(&self, other: &GSet<T>) -> bool {
-        self.elements.is_disjoint(&other.elements)
+impl<T, S> Extend<T> for GSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iterable: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for element in iterable {
+            self.elements.insert(element);
+        }
     }
 }
 
-impl<T> Crdt for GSet<T>
+impl<T, S> Crdt for GSet<T, S>
 where
     T: Clone + Eq + Hash,
+    S: BuildHasher,
 {
     type Operation = GSetOp<T>;
 
@@ -101,8 +390,8 @@ where
     /// # use crdt::set::GSet;
     /// use crdt::Crdt;
     ///
-    /// let mut local = GSet::new();
-    /// let mut remote = GSet::new();
+    /// let mut local: GSet<i32> = GSet::new();
+    /// let mut remote: GSet<i32> = GSet::new();
     ///
     /// local.insert(1i32);
     /// remote.insert(2);
@@ -110,8 +399,8 @@ where
     /// local.merge(remote);
     /// assert!(local.contains(&2));
     /// ```
-    fn merge(&mut self, other: GSet<T>) {
-        self.elements.extend(other.elements.into_iter());
+    fn merge(&mut self, other: GSet<T, S>) {
+        self.extend(other.into_iter());
     }
 
     /// Apply an insert operation to the set.
@@ -125,8 +414,8 @@ where
     /// ```
     /// # use crdt::set::GSet;
     /// # use crdt::Crdt;
-    /// let mut local = GSet::new();
-    /// let mut remote = GSet::new();
+    /// let mut local: GSet<i32> = GSet::new();
+    /// let mut remote: GSet<i32> = GSet::new();
     ///
     /// let op = remote.insert(13i32).expect("GSet should be empty.");
     ///
@@ -138,31 +427,34 @@ where
     }
 }
 
-impl<T> PartialEq for GSet<T>
+impl<T, S> PartialEq for GSet<T, S>
 where
-    T: Eq + Hash,
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
 {
-    fn eq(&self, other: &GSet<T>) -> bool {
-        self.elements == other.elements
+    fn eq(&self, other: &GSet<T, S>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
     }
 }
 
-impl<T> Eq for GSet<T>
+impl<T, S> Eq for GSet<T, S>
 where
-    T: Eq + Hash,
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
 {
 }
 
-impl<T> PartialOrd for GSet<T>
+impl<T, S> PartialOrd for GSet<T, S>
 where
-    T: Eq + Hash,
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
 {
-    fn partial_cmp(&self, other: &GSet<T>) -> Option<Ordering> {
-        if self.elements == other.elements {
+    fn partial_cmp(&self, other: &GSet<T, S>) -> Option<Ordering> {
+        if self == other {
             Some(Equal)
-        } else if self.elements.is_subset(&other.elements) {
+        } else if self.is_subset(other) {
             Some(Less)
-        } else if self.elements.is_superset(&other.elements) {
+        } else if other.is_subset(self) {
             Some(Greater)
         } else {
             None
@@ -170,38 +462,56 @@ where
     }
 }
 
-impl<T> Clone for GSet<T>
+impl<T, S> Clone for GSet<T, S>
 where
     T: Clone + Eq + Hash,
+    S: Clone,
 {
-    fn clone(&self) -> GSet<T> {
+    /// Cloning shares the backing trie through its reference-counted root, so
+    /// it is `O(1)` and copies no elements.
+    fn clone(&self) -> GSet<T, S> {
         GSet {
             elements: self.elements.clone(),
         }
     }
 }
 
+impl<T, S> Default for GSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn default() -> GSet<T, S> {
+        GSet::new()
+    }
+}
+
+impl<T, S> fmt::Debug for GSet<T, S>
+where
+    T: fmt::Debug + Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
 #[cfg(any(quickcheck, test))]
-impl<T> Arbitrary for GSet<T>
+impl<T, S> Arbitrary for GSet<T, S>
 where
     T: Arbitrary + Clone + Eq + Hash,
+    S: BuildHasher + Default + Clone + 'static,
 {
-    fn arbitrary<G>(g: &mut G) -> GSet<T>
+    fn arbitrary<G>(g: &mut G) -> GSet<T, S>
     where
         G: Gen,
     {
         let elements: Vec<T> = Arbitrary::arbitrary(g);
-        GSet {
-            elements: elements.into_iter().collect(),
-        }
+        elements.into_iter().collect()
     }
-    fn shrink(&self) -> Box<Iterator<Item = GSet<T>> + 'static> {
-        let elements: Vec<T> = self.elements.iter().cloned().collect();
-        Box::new(elements.shrink().map(|es| {
-            GSet {
-                elements: es.into_iter().collect(),
-            }
-        }))
+    fn shrink(&self) -> Box<Iterator<Item = GSet<T, S>> + 'static> {
+        let elements: Vec<T> = self.iter().cloned().collect();
+        Box::new(elements.shrink().map(|es| es.into_iter().collect()))
     }
 }
 
@@ -220,13 +530,225 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+    use std::hash::{BuildHasher, Hash};
+    use std::marker::PhantomData;
+
+    use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+    use super::{GSet, GSetOp};
+
+    /// A `GSet` serializes as a flat sequence of its elements.
+    impl<T, H> Serialize for GSet<T, H>
+    where
+        T: Serialize + Clone + Eq + Hash,
+        H: BuildHasher,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+            for element in self.iter() {
+                seq.serialize_element(element)?;
+            }
+            seq.end()
+        }
+    }
+
+    struct GSetVisitor<T, H> {
+        marker: PhantomData<(T, H)>,
+    }
+
+    impl<'de, T, H> Visitor<'de> for GSetVisitor<T, H>
+    where
+        T: Deserialize<'de> + Clone + Eq + Hash,
+        H: BuildHasher + Default,
+    {
+        type Value = GSet<T, H>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a sequence of grow-only set elements")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<GSet<T, H>, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut set = GSet::new();
+            while let Some(element) = seq.next_element()? {
+                set.insert(element);
+            }
+            Ok(set)
+        }
+    }
+
+    /// Deserialization reinserts every element through the normal insert path,
+    /// preserving the grow-only merge semantics.
+    impl<'de, T, H> Deserialize<'de> for GSet<T, H>
+    where
+        T: Deserialize<'de> + Clone + Eq + Hash,
+        H: BuildHasher + Default,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<GSet<T, H>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_seq(GSetVisitor {
+                marker: PhantomData,
+            })
+        }
+    }
+
+    /// A `GSetOp` serializes as its bare element; no enclosing record or field
+    /// name is written.
+    impl<T> Serialize for GSetOp<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.element.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for GSetOp<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<GSetOp<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Ok(GSetOp {
+                element: T::deserialize(deserializer)?,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher, Hash};
+
+    use rayon::iter::plumbing::UnindexedConsumer;
+    use rayon::iter::{
+        FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator,
+    };
+
+    use super::GSet;
+
+    /// Parallel iterator over shared references to the elements of a `GSet`.
+    pub struct ParIter<'a, T: 'a> {
+        entries: Vec<&'a T>,
+    }
+
+    impl<'a, T> ParallelIterator for ParIter<'a, T>
+    where
+        T: Sync,
+    {
+        type Item = &'a T;
+
+        fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where
+            C: UnindexedConsumer<Self::Item>,
+        {
+            self.entries.into_par_iter().drive_unindexed(consumer)
+        }
+    }
+
+    impl<T, S> GSet<T, S>
+    where
+        T: Clone + Eq + Hash + Send + Sync,
+        S: BuildHasher + Default,
+    {
+        /// A parallel iterator visiting all elements in an unspecified order.
+        pub fn par_iter(&self) -> ParIter<T> {
+            ParIter {
+                entries: self.iter().collect(),
+            }
+        }
+
+        /// Merge many replicas into a single set concurrently.
+        ///
+        /// Incoming replicas are folded into thread-local sets which are then
+        /// reduced by union. Because `GSet` merge is associative, commutative,
+        /// and idempotent, this produces exactly the same result as merging the
+        /// replicas one at a time.
+        pub fn merge_all<I>(replicas: I) -> GSet<T, S>
+        where
+            I: IntoParallelIterator<Item = GSet<T, S>>,
+        {
+            replicas
+                .into_par_iter()
+                .fold(HashSet::new, |mut acc, replica| {
+                    acc.extend(replica.into_iter());
+                    acc
+                })
+                .reduce(HashSet::new, |mut a, b| {
+                    a.extend(b);
+                    a
+                })
+                .into_iter()
+                .collect()
+        }
+    }
+
+    impl<'a, T, S> IntoParallelIterator for &'a GSet<T, S>
+    where
+        T: Clone + Eq + Hash + Send + Sync,
+        S: BuildHasher + Default,
+    {
+        type Item = &'a T;
+        type Iter = ParIter<'a, T>;
+
+        fn into_par_iter(self) -> ParIter<'a, T> {
+            self.par_iter()
+        }
+    }
+
+    impl<T, S> FromParallelIterator<T> for GSet<T, S>
+    where
+        T: Clone + Eq + Hash + Send,
+        S: BuildHasher + Default,
+    {
+        fn from_par_iter<I>(par_iter: I) -> GSet<T, S>
+        where
+            I: IntoParallelIterator<Item = T>,
+        {
+            let elements: HashSet<T> = par_iter.into_par_iter().collect();
+            elements.into_iter().collect()
+        }
+    }
+
+    impl<T, S> ParallelExtend<T> for GSet<T, S>
+    where
+        T: Clone + Eq + Hash + Send,
+        S: BuildHasher,
+    {
+        fn par_extend<I>(&mut self, par_iter: I)
+        where
+            I: IntoParallelIterator<Item = T>,
+        {
+            let elements: HashSet<T> = par_iter.into_par_iter().collect();
+            self.extend(elements);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use quickcheck::quickcheck;
 
     use {test, Crdt};
-    use super::{GSet, GSetOp};
+    use super::{GSet, GSetDelta, GSetOp};
 
     type C = GSet<u32>;
     type O = GSetOp<u32>;
@@ -251,10 +773,55 @@ mod test {
         quickcheck(test::ordering_equality::<C> as fn(C, C) -> bool);
     }
 
+    #[test]
+    fn test_delta_since_equals_full_merge() {
+        fn check_delta_since(a: GSet<u8>, b: GSet<u8>) -> bool {
+            let mut via_delta = a.clone();
+            via_delta.merge(b.delta_since(&a));
+
+            let mut via_full = a.clone();
+            via_full.merge(b);
+
+            via_delta == via_full
+        }
+        quickcheck(check_delta_since as fn(GSet<u8>, GSet<u8>) -> bool);
+    }
+
+    #[test]
+    fn test_delta_record_join_apply_equals_full_merge() {
+        fn check_delta_apply(a: GSet<u8>, b: GSet<u8>) -> bool {
+            let mut delta: GSetDelta<u8> = GSetDelta::new();
+            let mut scratch: GSet<u8> = GSet::new();
+            for element in &a {
+                if let Some(op) = scratch.insert(element.clone()) {
+                    delta.record(op);
+                }
+            }
+
+            let mut other: GSetDelta<u8> = GSetDelta::new();
+            let mut scratch: GSet<u8> = GSet::new();
+            for element in &b {
+                if let Some(op) = scratch.insert(element.clone()) {
+                    other.record(op);
+                }
+            }
+            delta.join(other);
+
+            let mut via_delta: GSet<u8> = GSet::new();
+            delta.apply(&mut via_delta);
+
+            let mut via_full = a.clone();
+            via_full.merge(b);
+
+            via_delta == via_full
+        }
+        quickcheck(check_delta_apply as fn(GSet<u8>, GSet<u8>) -> bool);
+    }
+
     #[test]
     fn test_local_insert() {
         fn check_local_insert(elements: Vec<u8>) -> bool {
-            let mut set = GSet::new();
+            let mut set: GSet<u8> = GSet::new();
             for element in elements.clone().into_iter() {
                 set.insert(element);
             }
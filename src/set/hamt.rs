@@ -0,0 +1,285 @@
+//! An immutable hash-array-mapped-trie set.
+//!
+//! The trie maps a key's hash into five-bit chunks, one per level, so every
+//! internal node fans out up to 32 ways. Nodes are reference counted and shared
+//! across versions: inserting a value copies only the nodes on the root-to-leaf
+//! path (path copying) and leaves the rest untouched. As a result `clone` is
+//! `O(1)` and inserting into a clone allocates only along the differing path,
+//! which is what lets a replica keep many historical versions cheaply.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::Arc;
+
+/// Number of hash bits consumed per trie level.
+const BITS: u64 = 5;
+/// Fan-out of an internal node (`2^BITS`).
+const WIDTH: usize = 1 << BITS;
+/// Mask selecting the chunk of hash bits for a level.
+const MASK: u64 = WIDTH as u64 - 1;
+
+fn hash_of<T, S>(value: &T, builder: &S) -> u64
+where
+    T: Hash,
+    S: BuildHasher,
+{
+    let mut hasher = builder.build_hasher();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+enum Node<T> {
+    /// An internal node. `bitmap` records which of the 32 slots are occupied;
+    /// `children` holds only the occupied ones, densely packed in slot order.
+    Branch {
+        bitmap: u32,
+        children: Vec<Arc<Node<T>>>,
+    },
+    /// A leaf holding every value whose hash reaches this position. Distinct
+    /// values normally live in their own leaves; a leaf only grows when two
+    /// values share a full 64-bit hash.
+    Leaf { hash: u64, values: Vec<T> },
+}
+
+impl<T: Clone> Node<T> {
+    fn insert(node: &Arc<Node<T>>, hash: u64, shift: u64, value: T) -> (Arc<Node<T>>, bool)
+    where
+        T: Eq,
+    {
+        match **node {
+            Node::Branch {
+                bitmap,
+                ref children,
+            } => {
+                let bit = 1u32 << ((hash >> shift) & MASK);
+                let pos = (bitmap & (bit - 1)).count_ones() as usize;
+                if bitmap & bit == 0 {
+                    let mut children = children.clone();
+                    children.insert(
+                        pos,
+                        Arc::new(Node::Leaf {
+                            hash,
+                            values: vec![value],
+                        }),
+                    );
+                    (
+                        Arc::new(Node::Branch {
+                            bitmap: bitmap | bit,
+                            children,
+                        }),
+                        true,
+                    )
+                } else {
+                    let (child, inserted) =
+                        Node::insert(&children[pos], hash, shift + BITS, value);
+                    if !inserted {
+                        return (node.clone(), false);
+                    }
+                    let mut children = children.clone();
+                    children[pos] = child;
+                    (Arc::new(Node::Branch { bitmap, children }), inserted)
+                }
+            }
+            Node::Leaf {
+                hash: lhash,
+                ref values,
+            } => {
+                if lhash == hash {
+                    if values.contains(&value) {
+                        (node.clone(), false)
+                    } else {
+                        let mut values = values.clone();
+                        values.push(value);
+                        (Arc::new(Node::Leaf { hash, values }), true)
+                    }
+                } else {
+                    (Node::split(node.clone(), lhash, hash, shift, value), true)
+                }
+            }
+        }
+    }
+
+    /// Split a leaf that collides with a new value at `shift`, pushing both
+    /// down into a fresh branch until their hash chunks diverge.
+    fn split(leaf: Arc<Node<T>>, lhash: u64, hash: u64, shift: u64, value: T) -> Arc<Node<T>> {
+        let idx1 = (lhash >> shift) & MASK;
+        let idx2 = (hash >> shift) & MASK;
+        if idx1 == idx2 {
+            let child = Node::split(leaf, lhash, hash, shift + BITS, value);
+            Arc::new(Node::Branch {
+                bitmap: 1u32 << idx1,
+                children: vec![child],
+            })
+        } else {
+            let new_leaf = Arc::new(Node::Leaf {
+                hash,
+                values: vec![value],
+            });
+            let children = if idx1 < idx2 {
+                vec![leaf, new_leaf]
+            } else {
+                vec![new_leaf, leaf]
+            };
+            Arc::new(Node::Branch {
+                bitmap: (1u32 << idx1) | (1u32 << idx2),
+                children,
+            })
+        }
+    }
+
+    fn contains(&self, hash: u64, shift: u64, value: &T) -> bool
+    where
+        T: Eq,
+    {
+        match *self {
+            Node::Branch {
+                bitmap,
+                ref children,
+            } => {
+                let bit = 1u32 << ((hash >> shift) & MASK);
+                if bitmap & bit == 0 {
+                    false
+                } else {
+                    let pos = (bitmap & (bit - 1)).count_ones() as usize;
+                    children[pos].contains(hash, shift + BITS, value)
+                }
+            }
+            Node::Leaf {
+                hash: lhash,
+                ref values,
+            } => lhash == hash && values.contains(value),
+        }
+    }
+}
+
+/// A persistent, structurally shared hash set, generic over its hasher.
+pub struct HamtSet<T, S = RandomState> {
+    root: Arc<Node<T>>,
+    len: usize,
+    hasher: S,
+}
+
+impl<T, S> HamtSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    /// Create an empty set using the default hasher.
+    pub fn new() -> HamtSet<T, S>
+    where
+        S: Default,
+    {
+        HamtSet::with_hasher(Default::default())
+    }
+
+    /// Create an empty set that hashes with `hasher`.
+    pub fn with_hasher(hasher: S) -> HamtSet<T, S> {
+        HamtSet {
+            root: Arc::new(Node::Branch {
+                bitmap: 0,
+                children: Vec::new(),
+            }),
+            len: 0,
+            hasher,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert `value`, returning `true` if it was not already present. Only the
+    /// nodes on the path to the new leaf are reallocated; the rest stay shared.
+    pub fn insert(&mut self, value: T) -> bool {
+        let hash = hash_of(&value, &self.hasher);
+        let (root, inserted) = Node::insert(&self.root, hash, 0, value);
+        self.root = root;
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// True if the set contains `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.root.contains(hash_of(value, &self.hasher), 0, value)
+    }
+
+    /// Create an empty set that shares this set's hasher.
+    pub fn empty_like(&self) -> HamtSet<T, S>
+    where
+        S: Clone,
+    {
+        HamtSet::with_hasher(self.hasher.clone())
+    }
+
+    /// An iterator visiting all elements in an unspecified order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut values = Vec::with_capacity(self.len);
+        collect(&self.root, &mut values);
+        Iter {
+            inner: values.into_iter(),
+        }
+    }
+}
+
+fn collect<'a, T>(node: &'a Node<T>, out: &mut Vec<&'a T>) {
+    match *node {
+        Node::Branch { ref children, .. } => {
+            for child in children {
+                collect(child, out);
+            }
+        }
+        Node::Leaf { ref values, .. } => out.extend(values.iter()),
+    }
+}
+
+/// An iterator over the elements of a `HamtSet`.
+pub struct Iter<'a, T: 'a> {
+    inner: ::std::vec::IntoIter<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// Cloning shares the whole trie through the reference-counted root, so it is
+/// `O(1)` and allocates nothing.
+impl<T, S> Clone for HamtSet<T, S>
+where
+    S: Clone,
+{
+    fn clone(&self) -> HamtSet<T, S> {
+        HamtSet {
+            root: self.root.clone(),
+            len: self.len,
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<T, S> Default for HamtSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn default() -> HamtSet<T, S> {
+        HamtSet::new()
+    }
+}
@@ -0,0 +1,8 @@
+//! Grow-only set CRDTs.
+
+pub mod gset;
+mod hamt;
+pub mod ordgset;
+
+pub use self::gset::{GSet, GSetDelta, GSetOp};
+pub use self::ordgset::{OrdGSet, OrdGSetOp};
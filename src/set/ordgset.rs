@@ -0,0 +1,372 @@
+use std::cmp::Ordering::{self, Equal, Greater, Less};
+use std::collections::btree_set;
+use std::collections::BTreeSet;
+use std::iter::FromIterator;
+use std::ops::RangeBounds;
+
+#[cfg(any(quickcheck, test))]
+use quickcheck::{Arbitrary, Gen};
+
+use Crdt;
+
+/// An ordered grow-only set.
+///
+/// Like `GSet`, but backed by a B-tree so elements are visited from lowest to
+/// highest and can be scanned by range. It only requires `Ord` rather than
+/// `Hash`, which makes it a natural fit for CRDTs keyed by timestamps or
+/// sortable identifiers.
+#[derive(Debug, Default)]
+pub struct OrdGSet<T>
+where
+    T: Ord,
+{
+    elements: BTreeSet<T>,
+}
+
+/// An insert operation over `OrdGSet` CRDTs.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrdGSetOp<T> {
+    element: T,
+}
+
+impl<T> OrdGSet<T>
+where
+    T: Clone + Ord,
+{
+    /// Create a new ordered grow-only set.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use crdt::set::OrdGSet;
+    ///
+    /// let mut set = OrdGSet::<i32>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> OrdGSet<T> {
+        OrdGSet {
+            elements: BTreeSet::new(),
+        }
+    }
+
+    /// Insert an element into an ordered grow-only set.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use crdt::set::OrdGSet;
+    ///
+    /// let mut set = OrdGSet::new();
+    /// set.insert("first-element");
+    /// assert!(set.contains(&"first-element"));
+    /// ```
+    pub fn insert(&mut self, element: T) -> Option<OrdGSetOp<T>> {
+        if self.elements.insert(element.clone()) {
+            Some(OrdGSetOp { element: element })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if the set contains the value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.elements.contains(value)
+    }
+
+    /// Returns true if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn is_subset(&self, other: &OrdGSet<T>) -> bool {
+        self.elements.is_subset(&other.elements)
+    }
+
+    pub fn is_disjoint(&self, other: &OrdGSet<T>) -> bool {
+        self.elements.is_disjoint(&other.elements)
+    }
+
+    /// An iterator visiting all elements in ascending order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    /// An iterator over a sub-range of elements in ascending order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use crdt::set::OrdGSet;
+    ///
+    /// let mut set = OrdGSet::new();
+    /// for i in 0..10 {
+    ///     set.insert(i);
+    /// }
+    /// let scanned: Vec<i32> = set.range(3..6).cloned().collect();
+    /// assert_eq!(vec![3, 4, 5], scanned);
+    /// ```
+    pub fn range<R>(&self, range: R) -> Range<'_, T>
+    where
+        R: RangeBounds<T>,
+    {
+        self.elements.range(range)
+    }
+}
+
+/// An iterator over the elements of an `OrdGSet`.
+pub type Iter<'a, T> = btree_set::Iter<'a, T>;
+
+/// An owning iterator over the elements of an `OrdGSet`.
+pub type IntoIter<T> = btree_set::IntoIter<T>;
+
+/// An iterator over a sub-range of an `OrdGSet`.
+pub type Range<'a, T> = btree_set::Range<'a, T>;
+
+impl<'a, T> IntoIterator for &'a OrdGSet<T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.elements.iter()
+    }
+}
+
+impl<T> IntoIterator for OrdGSet<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        self.elements.into_iter()
+    }
+}
+
+impl<T> FromIterator<T> for OrdGSet<T>
+where
+    T: Clone + Ord,
+{
+    fn from_iter<I>(iterable: I) -> OrdGSet<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        OrdGSet {
+            elements: iterable.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<T> for OrdGSet<T>
+where
+    T: Clone + Ord,
+{
+    fn extend<I>(&mut self, iterable: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.elements.extend(iterable);
+    }
+}
+
+impl<T> Crdt for OrdGSet<T>
+where
+    T: Clone + Ord,
+{
+    type Operation = OrdGSetOp<T>;
+
+    /// Merge a replica into the set.
+    ///
+    /// This method is used to perform state-based replication.
+    ///
+    /// ##### Example
+    ///
+    /// ```
+    /// # use crdt::set::OrdGSet;
+    /// use crdt::Crdt;
+    ///
+    /// let mut local = OrdGSet::new();
+    /// let mut remote = OrdGSet::new();
+    ///
+    /// local.insert(1i32);
+    /// remote.insert(2);
+    ///
+    /// local.merge(remote);
+    /// assert!(local.contains(&2));
+    /// ```
+    fn merge(&mut self, other: OrdGSet<T>) {
+        self.elements.extend(other.elements.into_iter());
+    }
+
+    /// Apply an insert operation to the set.
+    ///
+    /// This method is used to perform operation-based replication.
+    ///
+    /// Applying an operation to an `OrdGSet` is idempotent.
+    ///
+    /// ##### Example
+    ///
+    /// ```
+    /// # use crdt::set::OrdGSet;
+    /// # use crdt::Crdt;
+    /// let mut local = OrdGSet::new();
+    /// let mut remote = OrdGSet::new();
+    ///
+    /// let op = remote.insert(13i32).expect("OrdGSet should be empty.");
+    ///
+    /// local.apply(op);
+    /// assert!(local.contains(&13));
+    /// ```
+    fn apply(&mut self, op: OrdGSetOp<T>) {
+        self.insert(op.element);
+    }
+}
+
+impl<T> PartialEq for OrdGSet<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &OrdGSet<T>) -> bool {
+        self.elements == other.elements
+    }
+}
+
+impl<T> Eq for OrdGSet<T>
+where
+    T: Ord,
+{
+}
+
+impl<T> PartialOrd for OrdGSet<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &OrdGSet<T>) -> Option<Ordering> {
+        if self.elements == other.elements {
+            Some(Equal)
+        } else if self.elements.is_subset(&other.elements) {
+            Some(Less)
+        } else if self.elements.is_superset(&other.elements) {
+            Some(Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Clone for OrdGSet<T>
+where
+    T: Clone + Ord,
+{
+    fn clone(&self) -> OrdGSet<T> {
+        OrdGSet {
+            elements: self.elements.clone(),
+        }
+    }
+}
+
+#[cfg(any(quickcheck, test))]
+impl<T> Arbitrary for OrdGSet<T>
+where
+    T: Arbitrary + Clone + Ord,
+{
+    fn arbitrary<G>(g: &mut G) -> OrdGSet<T>
+    where
+        G: Gen,
+    {
+        let elements: Vec<T> = Arbitrary::arbitrary(g);
+        OrdGSet {
+            elements: elements.into_iter().collect(),
+        }
+    }
+    fn shrink(&self) -> Box<Iterator<Item = OrdGSet<T>> + 'static> {
+        let elements: Vec<T> = self.elements.iter().cloned().collect();
+        Box::new(elements.shrink().map(|es| {
+            OrdGSet {
+                elements: es.into_iter().collect(),
+            }
+        }))
+    }
+}
+
+#[cfg(any(quickcheck, test))]
+impl<T> Arbitrary for OrdGSetOp<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary<G: Gen>(g: &mut G) -> OrdGSetOp<T> {
+        OrdGSetOp {
+            element: Arbitrary::arbitrary(g),
+        }
+    }
+    fn shrink(&self) -> Box<Iterator<Item = OrdGSetOp<T>> + 'static> {
+        Box::new(self.element.shrink().map(|e| OrdGSetOp { element: e }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use quickcheck::quickcheck;
+
+    use {test, Crdt};
+    use super::{OrdGSet, OrdGSetOp};
+
+    type C = OrdGSet<u32>;
+    type O = OrdGSetOp<u32>;
+
+    #[test]
+    fn check_apply_is_commutative() {
+        quickcheck(test::apply_is_commutative::<C> as fn(C, Vec<O>) -> bool);
+    }
+
+    #[test]
+    fn check_merge_is_commutative() {
+        quickcheck(test::merge_is_commutative::<C> as fn(C, Vec<C>) -> bool);
+    }
+
+    #[test]
+    fn check_ordering_lte() {
+        quickcheck(test::ordering_lte::<C> as fn(C, C) -> bool);
+    }
+
+    #[test]
+    fn check_ordering_equality() {
+        quickcheck(test::ordering_equality::<C> as fn(C, C) -> bool);
+    }
+
+    #[test]
+    fn test_range_is_sorted() {
+        fn check_range_is_sorted(set: OrdGSet<u8>) -> bool {
+            let all: Vec<u8> = set.range(..).cloned().collect();
+            let mut sorted = all.clone();
+            sorted.sort();
+            all == sorted
+        }
+        quickcheck(check_range_is_sorted as fn(OrdGSet<u8>) -> bool);
+    }
+
+    #[test]
+    fn test_ordering_lt() {
+        fn check_ordering_lt(mut a: OrdGSet<u8>, b: OrdGSet<u8>) -> bool {
+            a.merge(b.clone());
+
+            let mut i = 0;
+            let mut success = None;
+            while success.is_none() {
+                success = a.insert(i);
+                i += 1;
+            }
+            a > b && b < a
+        }
+        quickcheck(check_ordering_lt as fn(OrdGSet<u8>, OrdGSet<u8>) -> bool);
+    }
+}